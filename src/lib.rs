@@ -10,6 +10,9 @@
 )]
 #![forbid(missing_docs)]
 
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
 /// A type of topology
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Topology {
@@ -18,6 +21,12 @@ pub enum Topology {
 
     /// A grid that wraps around, preserving the axis not moved in. e.g. Pacman
     Torus,
+
+    /// A grid that wraps around on one axis and is bounded on the other, like a tube
+    Cylinder {
+        /// Whether the grid wraps horizontally (on the x axis) rather than vertically
+        wrap_x: bool,
+    },
 }
 
 use Topology::*;
@@ -85,16 +94,57 @@ pub fn adjacent_cell(
         },
         Torus => match d {
             North => Some((x, y.checked_sub(1).unwrap_or(height - 1))),
-            South => Some((x, (y + 1) % width)),
+            South => Some((x, (y + 1) % height)),
             East => Some(((x + 1) % width, y)),
             West => Some((x.checked_sub(1).unwrap_or(width - 1), y)),
         },
+        Cylinder { wrap_x } => match d {
+            North => {
+                if wrap_x {
+                    Some((x, y.checked_sub(1)?))
+                } else {
+                    Some((x, y.checked_sub(1).unwrap_or(height - 1)))
+                }
+            }
+            South => {
+                if wrap_x {
+                    if y + 1 < height {
+                        Some((x, y + 1))
+                    } else {
+                        None
+                    }
+                } else {
+                    Some((x, (y + 1) % height))
+                }
+            }
+            East => {
+                if wrap_x {
+                    Some(((x + 1) % width, y))
+                } else if x + 1 < width {
+                    Some((x + 1, y))
+                } else {
+                    None
+                }
+            }
+            West => {
+                if wrap_x {
+                    Some((x.checked_sub(1).unwrap_or(width - 1), y))
+                } else {
+                    Some((x.checked_sub(1)?, y))
+                }
+            }
+        },
     }
 }
 
 /// Is a given point on an edge of a grid
 pub fn is_edge(t: Topology, width: usize, height: usize, x: usize, y: usize) -> bool {
-    t == Bounded && (x == 0 || x + 1 == width || y == 0 || y + 1 == height)
+    match t {
+        Bounded => x == 0 || x + 1 == width || y == 0 || y + 1 == height,
+        Cylinder { wrap_x: true } => y == 0 || y + 1 == height,
+        Cylinder { wrap_x: false } => x == 0 || x + 1 == width,
+        Torus => false,
+    }
 }
 
 /// Is a given point a corner of a grid
@@ -152,6 +202,471 @@ pub fn neighborhood(
     .flatten()
 }
 
+/// An owned 2d grid of cells, storing its own dimensions and [`Topology`]
+///
+/// This saves callers from having to maintain a separate `Vec<Vec<T>>` and re-thread
+/// `width`, `height`, and [`Topology`] through every free function in this crate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    topology: Topology,
+}
+
+impl<T> Grid<T> {
+    /// Creates a new grid of the given dimensions, filling every cell with clones of `fill`
+    pub fn new(width: usize, height: usize, topology: Topology, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+            topology,
+        }
+    }
+
+    /// Creates a new grid of the given dimensions, filling each cell by calling `f(x, y)`
+    pub fn from_generator(
+        width: usize,
+        height: usize,
+        topology: Topology,
+        mut f: impl FnMut(usize, usize) -> T,
+    ) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Self {
+            cells,
+            width,
+            height,
+            topology,
+        }
+    }
+
+    /// The width of the grid
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the grid
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The topology of the grid
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Is a given point within the bounds of the grid
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Gets a reference to the cell at `(x, y)`, if it is in bounds
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.in_bounds(x, y) {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the cell at `(x, y)`, if it is in bounds
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.in_bounds(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the cell at `(x, y)` to `value`, if it is in bounds. Returns `true` if the cell was
+    /// set
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over the points in a neighborhood around a point, paired with
+    /// references to their cells
+    ///
+    /// This reuses [`neighborhood`], so it respects the grid's stored [`Topology`]
+    pub fn neighborhood_cells(
+        &self,
+        x: usize,
+        y: usize,
+        n: Neighborhood,
+    ) -> impl Iterator<Item = (usize, usize, &T)> {
+        neighborhood(self.topology, self.width, self.height, x, y, n)
+            .filter_map(move |(nx, ny)| self.get(nx, ny).map(|cell| (nx, ny, cell)))
+    }
+
+    /// Returns an iterator over the points in a neighborhood around a point, paired with mutable
+    /// references to their cells
+    ///
+    /// This reuses [`neighborhood`], so it respects the grid's stored [`Topology`]
+    pub fn neighborhood_cells_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        n: Neighborhood,
+    ) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let width = self.width;
+        let height = self.height;
+        let mut seen = HashSet::new();
+        let coords: Vec<(usize, usize)> = neighborhood(self.topology, width, height, x, y, n)
+            .filter(|&(nx, ny)| nx < width && ny < height && seen.insert((nx, ny)))
+            .collect();
+        let ptr = self.cells.as_mut_ptr();
+        coords.into_iter().map(move |(nx, ny)| {
+            // SAFETY: `coords` holds only distinct, in-bounds coordinates (deduplicated via
+            // `seen` above), so each offset is unique and aliases no other reference yielded
+            // from this iterator.
+            (nx, ny, unsafe { &mut *ptr.add(ny * width + nx) })
+        })
+    }
+
+    /// Creates a grid from a block of text, mapping each character to a cell with `f`
+    ///
+    /// The width is inferred from the longest line and the height from the number of lines.
+    /// Lines shorter than the longest one are padded with `f(' ')`. The resulting grid uses
+    /// [`Topology::Bounded`]
+    pub fn from_str_with(s: &str, f: impl Fn(char) -> T) -> Self {
+        let lines: Vec<&str> = s.lines().collect();
+        let height = lines.len();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * height);
+        for line in &lines {
+            let mut chars = line.chars();
+            for _ in 0..width {
+                cells.push(f(chars.next().unwrap_or(' ')));
+            }
+        }
+
+        Self {
+            cells,
+            width,
+            height,
+            topology: Bounded,
+        }
+    }
+
+    /// Renders the grid back into text, mapping each cell to a character with `f`
+    ///
+    /// This is the inverse of [`Grid::from_str_with`], and is mostly useful for debugging
+    pub fn to_string_with(&self, f: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                out.push(f(&self.cells[y * self.width + x]));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Finds the cheapest path between two points using Dijkstra's algorithm
+///
+/// `cost` gives the cost of entering a point, or `None` if it is impassable. Neighbors are found
+/// with [`neighborhood`], so the search respects `t`. Returns the total cost and the path from
+/// `start` to `goal`, inclusive, or `None` if `goal` is unreachable
+pub fn shortest_path(
+    t: Topology,
+    width: usize,
+    height: usize,
+    start: (usize, usize),
+    goal: (usize, usize),
+    n: Neighborhood,
+    cost: impl Fn((usize, usize)) -> Option<u64>,
+) -> Option<(u64, Vec<(usize, usize)>)> {
+    shortest_path_astar(t, width, height, start, goal, n, cost, |_| 0)
+}
+
+/// Finds the cheapest path between two points using the A* algorithm
+///
+/// Like [`shortest_path`], but also takes an admissible heuristic `h` estimating the remaining
+/// cost from a point to `goal`, which is used to guide the search towards the goal
+#[allow(clippy::too_many_arguments)]
+pub fn shortest_path_astar(
+    t: Topology,
+    width: usize,
+    height: usize,
+    start: (usize, usize),
+    goal: (usize, usize),
+    n: Neighborhood,
+    cost: impl Fn((usize, usize)) -> Option<u64>,
+    h: impl Fn((usize, usize)) -> u64,
+) -> Option<(u64, Vec<(usize, usize)>)> {
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+
+    dist.insert(start, 0);
+    frontier.push(Reverse((h(start), start)));
+
+    while let Some(Reverse((priority, u))) = frontier.pop() {
+        if priority > dist[&u] + h(u) {
+            continue;
+        }
+
+        if u == goal {
+            let mut path = vec![u];
+            let mut current = u;
+            while let Some(&p) = prev.get(&current) {
+                path.push(p);
+                current = p;
+            }
+            path.reverse();
+            return Some((dist[&u], path));
+        }
+
+        for v in neighborhood(t, width, height, u.0, u.1, n) {
+            let Some(step_cost) = cost(v) else {
+                continue;
+            };
+
+            let new_dist = dist[&u] + step_cost;
+            if new_dist < *dist.get(&v).unwrap_or(&u64::MAX) {
+                dist.insert(v, new_dist);
+                prev.insert(v, u);
+                frontier.push(Reverse((new_dist + h(v), v)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds every point reachable from `seed` by repeatedly stepping to a neighbor for which
+/// `connected` returns `true`
+///
+/// Neighbors are found with [`neighborhood`], so the search respects `t`. `connected` is called
+/// as `connected(current, neighbor)`; `seed` itself is always included, without a check
+pub fn flood_fill(
+    t: Topology,
+    width: usize,
+    height: usize,
+    seed: (usize, usize),
+    n: Neighborhood,
+    connected: impl Fn((usize, usize), (usize, usize)) -> bool,
+) -> HashSet<(usize, usize)> {
+    let mut visited = HashSet::new();
+    let mut frontier = VecDeque::new();
+
+    visited.insert(seed);
+    frontier.push_back(seed);
+
+    while let Some(current) = frontier.pop_front() {
+        for neighbor in neighborhood(t, width, height, current.0, current.1, n) {
+            if !visited.contains(&neighbor) && connected(current, neighbor) {
+                visited.insert(neighbor);
+                frontier.push_back(neighbor);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Partitions every point of the grid into connected components, using [`flood_fill`] from each
+/// point not already assigned to one
+pub fn connected_components(
+    t: Topology,
+    width: usize,
+    height: usize,
+    n: Neighborhood,
+    connected: impl Fn((usize, usize), (usize, usize)) -> bool,
+) -> Vec<HashSet<(usize, usize)>> {
+    let mut labeled: HashSet<(usize, usize)> = HashSet::new();
+    let mut components = Vec::new();
+
+    for point in points(width, height) {
+        if labeled.contains(&point) {
+            continue;
+        }
+
+        let component = flood_fill(t, width, height, point, n, &connected);
+        labeled.extend(&component);
+        components.push(component);
+    }
+
+    components
+}
+
+/// A point in a `D`-dimensional grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PositionND<const D: usize> {
+    /// The coordinate of the point along each axis
+    pub points: [isize; D],
+}
+
+impl<const D: usize> PositionND<D> {
+    /// Creates a new position from its per-axis coordinates
+    pub fn new(points: [isize; D]) -> Self {
+        Self { points }
+    }
+}
+
+/// Returns an iterator over the points of a `D`-dimensional grid of the given `shape`
+pub fn points_nd<const D: usize>(shape: [usize; D]) -> PointsND<D> {
+    let next = if shape.contains(&0) {
+        None
+    } else {
+        Some([0; D])
+    };
+
+    PointsND { shape, next }
+}
+
+/// Iterator over every point of a `D`-dimensional grid, returned by [`points_nd`]
+#[derive(Debug, Clone)]
+pub struct PointsND<const D: usize> {
+    shape: [usize; D],
+    next: Option<[usize; D]>,
+}
+
+impl<const D: usize> Iterator for PointsND<D> {
+    type Item = PositionND<D>;
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        let mut carry = current;
+        self.next = None;
+        for axis in (0..D).rev() {
+            carry[axis] += 1;
+            if carry[axis] < self.shape[axis] {
+                self.next = Some(carry);
+                break;
+            }
+            carry[axis] = 0;
+        }
+
+        Some(PositionND {
+            points: current.map(|c| c as isize),
+        })
+    }
+}
+
+/// Get the point adjacent to `p` in a `D`-dimensional grid of the given `shape`, offset by
+/// `offset` along each axis
+///
+/// `offset` is applied one axis at a time, each wrapped via modulo for [`Topology::Torus`] or
+/// bounds-checked for [`Topology::Bounded`]. For [`Topology::Cylinder`], axis `0` wraps exactly
+/// when `wrap_x` is set and every other axis wraps exactly when it is not, generalizing the 2d
+/// "wrap one axis, bound the rest" behavior
+#[allow(clippy::cast_possible_wrap)]
+pub fn adjacent_cell_nd<const D: usize>(
+    t: Topology,
+    shape: [usize; D],
+    p: PositionND<D>,
+    offset: [isize; D],
+) -> Option<PositionND<D>> {
+    let mut points = [0isize; D];
+
+    for axis in 0..D {
+        let coord = p.points[axis] + offset[axis];
+        let wraps = match t {
+            Bounded => false,
+            Torus => true,
+            Cylinder { wrap_x } => (axis == 0) == wrap_x,
+        };
+
+        points[axis] = if wraps {
+            coord.rem_euclid(shape[axis] as isize)
+        } else if coord < 0 || coord >= shape[axis] as isize {
+            return None;
+        } else {
+            coord
+        };
+    }
+
+    Some(PositionND { points })
+}
+
+/// All `3^D - 1` offsets of the square neighborhood, generated by counting in base 3 over `D`
+/// digits and mapping each digit `0, 1, 2` to an offset of `-1, 0, +1`
+#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+fn square_offsets_nd<const D: usize>() -> Vec<[isize; D]> {
+    let total = 3usize.pow(D as u32);
+    let mut offsets = Vec::with_capacity(total - 1);
+
+    for i in 0..total {
+        let mut n = i;
+        let mut offset = [0isize; D];
+        for axis in &mut offset {
+            *axis = (n % 3) as isize - 1;
+            n /= 3;
+        }
+        if offset != [0; D] {
+            offsets.push(offset);
+        }
+    }
+
+    offsets
+}
+
+/// The `2 * D` unit-axis offsets of the orthogonal neighborhood
+fn orthogonal_offsets_nd<const D: usize>() -> Vec<[isize; D]> {
+    let mut offsets = Vec::with_capacity(2 * D);
+
+    for axis in 0..D {
+        let mut plus = [0isize; D];
+        plus[axis] = 1;
+        offsets.push(plus);
+
+        let mut minus = [0isize; D];
+        minus[axis] = -1;
+        offsets.push(minus);
+    }
+
+    offsets
+}
+
+/// Returns an iterator over the points in a neighborhood around `p`, in a `D`-dimensional grid
+/// of the given `shape`
+pub fn neighborhood_nd<const D: usize>(
+    t: Topology,
+    shape: [usize; D],
+    p: PositionND<D>,
+    n: Neighborhood,
+) -> impl Iterator<Item = PositionND<D>> {
+    let offsets = match n {
+        Square => square_offsets_nd(),
+        Orthogonal => orthogonal_offsets_nd(),
+        Diagonal => {
+            let orthogonal = orthogonal_offsets_nd::<D>();
+            square_offsets_nd()
+                .into_iter()
+                .filter(|offset| !orthogonal.contains(offset))
+                .collect()
+        }
+    };
+
+    offsets
+        .into_iter()
+        .filter_map(move |offset| adjacent_cell_nd(t, shape, p, offset))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +701,33 @@ mod tests {
         assert_eq!(adjacent_cell(Torus, 3, 3, 1, 1, West), Some((0, 1)));
     }
 
+    #[test]
+    fn adjacent_torus_south_wraps_on_height() {
+        // A non-square grid catches South wrapping modulo the wrong dimension.
+        assert_eq!(adjacent_cell(Torus, 5, 2, 0, 1, South), Some((0, 0)));
+    }
+
+    #[test]
+    fn adjacent_cylinder() {
+        // Wraps horizontally, bounded vertically.
+        let wrap_x = Cylinder { wrap_x: true };
+        assert_eq!(adjacent_cell(wrap_x, 3, 3, 0, 0, West), Some((2, 0)));
+        assert_eq!(adjacent_cell(wrap_x, 3, 3, 0, 0, North), None);
+
+        // Wraps vertically, bounded horizontally.
+        let wrap_y = Cylinder { wrap_x: false };
+        assert_eq!(adjacent_cell(wrap_y, 3, 3, 0, 0, North), Some((0, 2)));
+        assert_eq!(adjacent_cell(wrap_y, 3, 3, 0, 0, West), None);
+    }
+
+    #[test]
+    fn cylinder_edge_and_corner() {
+        let wrap_x = Cylinder { wrap_x: true };
+        assert!(is_edge(wrap_x, 3, 3, 1, 0));
+        assert!(!is_edge(wrap_x, 3, 3, 0, 1));
+        assert!(!is_corner(wrap_x, 3, 3, 0, 0));
+    }
+
     #[test]
     fn edge() {
         assert!(is_edge(Bounded, 3, 3, 1, 0));
@@ -223,4 +765,183 @@ mod tests {
             [(0, 1), (1, 0), (1, 1)],
         );
     }
+
+    #[test]
+    fn grid_get_set() {
+        let mut grid = Grid::new(3, 2, Bounded, 0);
+        assert_eq!(grid.get(1, 1), Some(&0));
+        assert_eq!(grid.get(3, 0), None);
+
+        assert!(grid.set(1, 1, 5));
+        assert_eq!(grid.get(1, 1), Some(&5));
+        assert!(!grid.set(3, 0, 5));
+    }
+
+    #[test]
+    fn grid_from_generator() {
+        let grid = Grid::from_generator(2, 2, Bounded, |x, y| x + y);
+        assert_eq!(grid.get(0, 0), Some(&0));
+        assert_eq!(grid.get(1, 0), Some(&1));
+        assert_eq!(grid.get(0, 1), Some(&1));
+        assert_eq!(grid.get(1, 1), Some(&2));
+    }
+
+    #[test]
+    fn grid_neighborhood_cells() {
+        let mut grid = Grid::from_generator(3, 3, Torus, |x, y| x * 10 + y);
+        let mut cells: Vec<(usize, usize, usize)> = grid
+            .neighborhood_cells(0, 0, Orthogonal)
+            .map(|(x, y, v)| (x, y, *v))
+            .collect();
+        cells.sort_unstable();
+        assert_eq!(cells, [(0, 1, 1), (0, 2, 2), (1, 0, 10), (2, 0, 20)]);
+
+        for (_, _, cell) in grid.neighborhood_cells_mut(1, 1, Orthogonal) {
+            *cell = 0;
+        }
+        assert_eq!(grid.get(1, 0), Some(&0));
+    }
+
+    #[test]
+    fn grid_from_str_with() {
+        let grid = Grid::from_str_with("#.\n.#\n", |c| c == '#');
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 0), Some(&true));
+        assert_eq!(grid.get(1, 0), Some(&false));
+        assert_eq!(grid.get(0, 1), Some(&false));
+        assert_eq!(grid.get(1, 1), Some(&true));
+    }
+
+    #[test]
+    fn grid_to_string_with() {
+        let grid = Grid::from_str_with("#.\n.#\n", |c| c == '#');
+        assert_eq!(
+            grid.to_string_with(|&b| if b { '#' } else { '.' }),
+            "#.\n.#\n"
+        );
+    }
+
+    #[test]
+    fn grid_from_str_with_ragged() {
+        let grid = Grid::from_str_with("##\n#\n", |c| c);
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.get(1, 1), Some(&' '));
+    }
+
+    #[test]
+    fn shortest_path_simple() {
+        let (cost, path) =
+            shortest_path(Bounded, 3, 1, (0, 0), (2, 0), Orthogonal, |_| Some(1)).unwrap();
+        assert_eq!(cost, 2);
+        assert_eq!(path, [(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn shortest_path_around_wall() {
+        // . # .
+        // . # .
+        // . . .
+        let wall = |(x, y): (usize, usize)| {
+            if (x, y) == (1, 0) || (x, y) == (1, 1) {
+                None
+            } else {
+                Some(1)
+            }
+        };
+        let (cost, _) = shortest_path(Bounded, 3, 3, (0, 0), (2, 0), Orthogonal, wall).unwrap();
+        assert_eq!(cost, 6);
+    }
+
+    #[test]
+    fn shortest_path_unreachable() {
+        let wall = |(x, _): (usize, usize)| if x == 1 { None } else { Some(1) };
+        assert_eq!(
+            shortest_path(Bounded, 3, 1, (0, 0), (2, 0), Orthogonal, wall),
+            None
+        );
+    }
+
+    #[test]
+    fn shortest_path_astar_matches_dijkstra() {
+        let manhattan = |(x, y): (usize, usize)| (x.abs_diff(2) + y.abs_diff(2)) as u64;
+        let (cost, _) = shortest_path_astar(
+            Bounded,
+            3,
+            3,
+            (0, 0),
+            (2, 2),
+            Orthogonal,
+            |_| Some(1),
+            manhattan,
+        )
+        .unwrap();
+        assert_eq!(cost, 4);
+    }
+
+    #[test]
+    fn flood_fill_all_connected() {
+        let region = flood_fill(Bounded, 3, 3, (0, 0), Orthogonal, |_, _| true);
+        assert_eq!(region.len(), 9);
+    }
+
+    #[test]
+    fn flood_fill_stops_at_boundary() {
+        // Two separate halves, split between columns 1 and 2.
+        let region = flood_fill(Bounded, 4, 2, (0, 0), Orthogonal, |(x1, _), (x2, _)| {
+            (x1 < 2) == (x2 < 2)
+        });
+        assert_eq!(region, HashSet::from([(0, 0), (0, 1), (1, 0), (1, 1)]));
+    }
+
+    #[test]
+    fn connected_components_splits_grid() {
+        let components = connected_components(Bounded, 4, 2, Orthogonal, |(x1, _), (x2, _)| {
+            (x1 < 2) == (x2 < 2)
+        });
+        assert_eq!(components.len(), 2);
+        assert!(components.iter().all(|c| c.len() == 4));
+    }
+
+    #[test]
+    fn points_nd_counts() {
+        assert_eq!(points_nd([3, 3, 3]).count(), 27);
+        assert_eq!(points_nd([3, 0, 3]).count(), 0);
+    }
+
+    #[test]
+    fn adjacent_cell_nd_bounded() {
+        let p = PositionND::new([1, 1, 1]);
+        assert_eq!(
+            adjacent_cell_nd(Bounded, [3, 3, 3], p, [0, 0, -1]),
+            Some(PositionND::new([1, 1, 0]))
+        );
+        assert_eq!(adjacent_cell_nd(Bounded, [3, 3, 3], p, [0, 0, -2]), None);
+    }
+
+    #[test]
+    fn adjacent_cell_nd_torus() {
+        let p = PositionND::new([0, 0, 0]);
+        assert_eq!(
+            adjacent_cell_nd(Torus, [3, 3, 3], p, [0, 0, -1]),
+            Some(PositionND::new([0, 0, 2]))
+        );
+    }
+
+    #[test]
+    fn neighborhood_nd_orthogonal() {
+        let p = PositionND::new([1, 1, 1]);
+        assert_eq!(
+            neighborhood_nd(Bounded, [3, 3, 3], p, Orthogonal).count(),
+            6
+        );
+    }
+
+    #[test]
+    fn neighborhood_nd_square_and_diagonal() {
+        let p = PositionND::new([1, 1]);
+        assert_eq!(neighborhood_nd(Torus, [3, 3], p, Square).count(), 8);
+        assert_eq!(neighborhood_nd(Torus, [3, 3], p, Orthogonal).count(), 4);
+        assert_eq!(neighborhood_nd(Torus, [3, 3], p, Diagonal).count(), 4);
+    }
 }